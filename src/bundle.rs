@@ -0,0 +1,344 @@
+use crate::path::{VfsPath, VfsPathBuf};
+use crate::{DirEntry, Metadata, Mount, VfsFile};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Result, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+// Magic bytes identifying a bundle file, followed by a `u32` format version.
+const MAGIC: &[u8; 4] = b"VFSB";
+const VERSION: u32 = 1;
+
+// Builds a single-file archive out of a physical directory: every file's bytes are appended to
+// one backing blob, and a manifest mapping each canonicalized virtual path to its `(offset, len)`
+// within that blob is written ahead of it. No directory tree is serialized; directory listings
+// are derived from the manifest's paths on each `read_dir`/`stat` call instead.
+#[derive(Debug, Default)]
+pub struct BundleBuilder {
+    entries: Vec<(VfsPathBuf, Vec<u8>)>,
+}
+
+impl BundleBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Recursively stages every file under `folder` into the bundle, rooted at `target`.
+    pub fn add_physical_dir(&mut self, target: &VfsPath, folder: &Path) -> Result<()> {
+        for entry in std::fs::read_dir(folder)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+
+            let mut segments: Vec<&str> = target.iter().collect();
+            segments.push(&name);
+            let child_target: VfsPathBuf = segments.into_iter().collect();
+
+            if entry.file_type()?.is_dir() {
+                self.add_physical_dir(&child_target, &entry.path())?;
+            } else {
+                let canonical = child_target
+                    .validate()
+                    .map_err(|_| std::io::Error::from(ErrorKind::InvalidInput))?
+                    .canonicalize();
+
+                if self.entries.iter().any(|(path, _)| *path == canonical) {
+                    return Err(ErrorKind::AlreadyExists.into());
+                }
+
+                self.entries.push((canonical, std::fs::read(entry.path())?));
+            }
+        }
+        Ok(())
+    }
+
+    // Writes the bundle (header, manifest, then the concatenated data blob) to `output`.
+    pub fn build(self, output: &Path) -> Result<()> {
+        let mut file = File::create(output)?;
+
+        file.write_all(MAGIC)?;
+        file.write_all(&VERSION.to_le_bytes())?;
+        file.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+
+        let mut offset = 0u64;
+        for (path, bytes) in &self.entries {
+            let path_bytes = path.to_str().as_bytes();
+            file.write_all(&(path_bytes.len() as u32).to_le_bytes())?;
+            file.write_all(path_bytes)?;
+            file.write_all(&offset.to_le_bytes())?;
+            file.write_all(&(bytes.len() as u64).to_le_bytes())?;
+            offset += bytes.len() as u64;
+        }
+
+        for (_, bytes) in &self.entries {
+            file.write_all(bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+// A read-only mount backed by a single bundle file produced by `BundleBuilder`. Every `open`
+// re-opens the backing file and hands back a handle bounded to that entry's `[offset, offset +
+// len)` region, so large bundles never need to be read into memory up front.
+#[derive(Debug)]
+pub struct BundleMount {
+    data_path: PathBuf,
+    data_start: u64,
+    file_offsets: HashMap<VfsPathBuf, (u64, u64)>,
+}
+
+impl BundleMount {
+    pub fn new(path: &Path) -> Result<Self> {
+        let mut file = File::open(path)?;
+
+        let mut magic = [0u8; 4];
+        file.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let version = read_u32(&mut file)?;
+        if version != VERSION {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let entry_count = read_u32(&mut file)?;
+        let mut file_offsets = HashMap::with_capacity(entry_count as usize);
+        for _ in 0..entry_count {
+            let path_len = read_u32(&mut file)? as usize;
+            let mut path_bytes = vec![0u8; path_len];
+            file.read_exact(&mut path_bytes)?;
+            let path = String::from_utf8(path_bytes)
+                .map_err(|_| std::io::Error::from(ErrorKind::InvalidData))?;
+
+            let offset = read_u64(&mut file)?;
+            let len = read_u64(&mut file)?;
+            file_offsets.insert(VfsPathBuf::from(path), (offset, len));
+        }
+
+        let data_start = file.stream_position()?;
+
+        Ok(Self {
+            data_path: path.to_owned(),
+            data_start,
+            file_offsets,
+        })
+    }
+
+    // Lists the immediate children of `dir` by scanning the flat manifest for paths that start
+    // with it, same as `VirtualDir::list` does for the in-memory tree.
+    fn children_of(&self, dir: &VfsPath) -> impl Iterator<Item = (&str, Option<u64>)> {
+        let prefix = if dir.to_str() == "/" {
+            "/".to_owned()
+        } else {
+            format!("{}/", dir.to_str())
+        };
+
+        self.file_offsets.iter().filter_map(move |(path, (_, len))| {
+            let rest = path.to_str().strip_prefix(&prefix)?;
+            if rest.is_empty() {
+                return None;
+            }
+            match rest.split_once('/') {
+                Some((name, _)) => Some((name, None)),
+                None => Some((rest, Some(*len))),
+            }
+        })
+    }
+}
+
+impl Mount for BundleMount {
+    fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>> {
+        let canonical = path.canonicalize();
+        let (offset, len) = *self
+            .file_offsets
+            .get(&canonical)
+            .ok_or_else(|| std::io::Error::from(ErrorKind::NotFound))?;
+
+        Ok(Box::new(BoundedFile {
+            file: File::open(&self.data_path)?,
+            start: self.data_start + offset,
+            len,
+            pos: 0,
+        }))
+    }
+
+    fn read_dir(&self, path: &VfsPath) -> Result<Vec<DirEntry>> {
+        let canonical = path.canonicalize();
+        let mut seen = std::collections::HashSet::new();
+        let mut entries = Vec::new();
+
+        for (name, len) in self.children_of(&canonical) {
+            if !seen.insert(name.to_owned()) {
+                continue;
+            }
+            entries.push(DirEntry {
+                name: name.to_owned(),
+                metadata: match len {
+                    Some(len) => Metadata {
+                        is_file: true,
+                        len,
+                        ..Default::default()
+                    },
+                    None => Metadata {
+                        is_dir: true,
+                        ..Default::default()
+                    },
+                },
+            });
+        }
+
+        if entries.is_empty() && !self.file_offsets.contains_key(&canonical) {
+            Err(ErrorKind::NotFound.into())
+        } else {
+            Ok(entries)
+        }
+    }
+
+    fn stat(&self, path: &VfsPath) -> Result<Metadata> {
+        let canonical = path.canonicalize();
+
+        if let Some((_, len)) = self.file_offsets.get(&canonical) {
+            return Ok(Metadata {
+                is_file: true,
+                len: *len,
+                ..Default::default()
+            });
+        }
+
+        if self.children_of(&canonical).next().is_some() {
+            return Ok(Metadata {
+                is_dir: true,
+                ..Default::default()
+            });
+        }
+
+        Err(ErrorKind::NotFound.into())
+    }
+}
+
+// A file handle bounded to a `[start, start + len)` byte region of a shared backing file, used to
+// read a single bundle entry out of the concatenated blob without exposing its neighbors.
+#[derive(Debug)]
+struct BoundedFile {
+    file: File,
+    start: u64,
+    len: u64,
+    pos: u64,
+}
+
+impl Read for BoundedFile {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = self.len.saturating_sub(self.pos);
+        if remaining == 0 {
+            return Ok(0);
+        }
+
+        let max = remaining.min(buf.len() as u64) as usize;
+        self.file.seek(SeekFrom::Start(self.start + self.pos))?;
+        let read = self.file.read(&mut buf[..max])?;
+        self.pos += read as u64;
+        Ok(read)
+    }
+}
+
+impl Seek for BoundedFile {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.len as i64 + offset,
+            SeekFrom::Current(offset) => self.pos as i64 + offset,
+        };
+
+        if new_pos < 0 {
+            return Err(ErrorKind::InvalidInput.into());
+        }
+
+        self.pos = new_pos as u64;
+        Ok(self.pos)
+    }
+}
+
+fn read_u32(file: &mut File) -> Result<u32> {
+    let mut buf = [0u8; 4];
+    file.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(file: &mut File) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    file.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VFS;
+    use std::io::Read;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn bundle_roundtrip() {
+        let source = scratch_dir("vfs_bundle_source");
+        std::fs::write(source.join("a.txt"), b"hello").unwrap();
+        std::fs::create_dir(source.join("sub")).unwrap();
+        std::fs::write(source.join("sub/b.txt"), b"world").unwrap();
+
+        let bundle_path = std::env::temp_dir().join("vfs_bundle_roundtrip.bundle");
+
+        let mut builder = BundleBuilder::new();
+        builder
+            .add_physical_dir(VfsPath::new("/"), &source)
+            .unwrap();
+        builder.build(&bundle_path).unwrap();
+
+        let mut vfs = VFS::new();
+        vfs.mount_bundle(VfsPath::new("/assets"), &bundle_path)
+            .unwrap();
+
+        let mut file = vfs.open(VfsPath::new("/assets/a.txt")).unwrap();
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).unwrap();
+        assert_eq!(contents, "hello");
+
+        let mut file = vfs.open(VfsPath::new("/assets/sub/b.txt")).unwrap();
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).unwrap();
+        assert_eq!(contents, "world");
+
+        assert!(vfs.open(VfsPath::new("/assets/missing.txt")).is_err());
+
+        let mut entries = vfs.read_dir(VfsPath::new("/assets")).unwrap();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["a.txt", "sub"]);
+
+        std::fs::remove_dir_all(&source).unwrap();
+        std::fs::remove_file(&bundle_path).unwrap();
+    }
+
+    #[test]
+    fn bundle_rejects_duplicate_paths() {
+        let source = scratch_dir("vfs_bundle_dup_source");
+        std::fs::write(source.join("a.txt"), b"one").unwrap();
+
+        let mut builder = BundleBuilder::new();
+        builder
+            .add_physical_dir(VfsPath::new("/"), &source)
+            .unwrap();
+        let err = builder
+            .add_physical_dir(VfsPath::new("/"), &source)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+        std::fs::remove_dir_all(&source).unwrap();
+    }
+}
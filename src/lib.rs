@@ -1,5 +1,5 @@
 // We use io::Result as it's the most fitting for our purpose and no reason to reinvent the wheel
-use std::io::{ErrorKind, Read, Result};
+use std::io::{ErrorKind, Read, Result, Seek};
 use std::path::Path;
 
 pub mod path;
@@ -8,6 +8,11 @@ use path::VfsPath;
 pub mod physical;
 use physical::PhysicalMount;
 
+pub mod bundle;
+use bundle::BundleMount;
+
+pub mod overlay;
+
 #[derive(Debug, Default)]
 pub struct VFS {
     root: VirtualDir,
@@ -23,6 +28,12 @@ impl VFS {
         Ok(())
     }
 
+    pub fn mount_bundle(&mut self, target: &VfsPath, bundle_file: &Path) -> Result<()> {
+        let bundle = Box::new(BundleMount::new(bundle_file)?);
+        self.mount(target, bundle);
+        Ok(())
+    }
+
     pub fn mount(&mut self, target: &VfsPath, mount: Box<dyn Mount>) {
         if target.iter().next().is_none() {
             // If there is no path, then root was pointed to, i.e. "/"
@@ -37,9 +48,41 @@ impl VFS {
 // VFS is a mount because it implements all the same functions anyway,
 // and to create the possibility of recursive structures
 impl Mount for VFS {
-    fn open(&self, path: &VfsPath) -> Result<Box<dyn Read>> {
+    fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>> {
         self.root.open(path)
     }
+
+    fn create_file(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.root.create_file(path, options)
+    }
+
+    fn remove_file(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        self.root.remove_file(path, options)
+    }
+
+    fn create_dir(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.root.create_dir(path, options)
+    }
+
+    fn remove_dir(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        self.root.remove_dir(path, options)
+    }
+
+    fn rename(&self, from: &VfsPath, to: &VfsPath, options: RenameOptions) -> Result<()> {
+        self.root.rename(from, to, options)
+    }
+
+    fn copy(&self, from: &VfsPath, to: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.root.copy(from, to, options)
+    }
+
+    fn read_dir(&self, path: &VfsPath) -> Result<Vec<DirEntry>> {
+        self.root.read_dir(path)
+    }
+
+    fn stat(&self, path: &VfsPath) -> Result<Metadata> {
+        self.root.stat(path)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -75,8 +118,193 @@ impl VirtualDir {
         }
     }
 
-    fn open(&self, path: &VfsPath) -> Result<Box<dyn Read>> {
-        let mut file = Err(ErrorKind::NotFound.into());
+    fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>> {
+        self.resolve(path, &|mount, tail| mount.open(tail))
+    }
+
+    fn create_file(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.resolve(path, &|mount, tail| mount.create_file(tail, options))
+    }
+
+    fn remove_file(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        self.resolve(path, &|mount, tail| mount.remove_file(tail, options))
+    }
+
+    fn create_dir(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.resolve(path, &|mount, tail| mount.create_dir(tail, options))
+    }
+
+    fn remove_dir(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        self.resolve(path, &|mount, tail| mount.remove_dir(tail, options))
+    }
+
+    fn rename(&self, from: &VfsPath, to: &VfsPath, options: RenameOptions) -> Result<()> {
+        // TODO: this assumes `from` and `to` resolve into the same mount; cross-mount
+        // rename/copy is not supported
+        self.resolve2(from, to, &|mount, from_tail, to_tail| {
+            mount.rename(from_tail, to_tail, options)
+        })
+    }
+
+    fn copy(&self, from: &VfsPath, to: &VfsPath, options: CreateOptions) -> Result<()> {
+        // TODO: see the note on `rename` above, the same same-mount assumption applies here
+        self.resolve2(from, to, &|mount, from_tail, to_tail| {
+            mount.copy(from_tail, to_tail, options)
+        })
+    }
+
+    fn read_dir(&self, path: &VfsPath) -> Result<Vec<DirEntry>> {
+        match path.take_head() {
+            // There is still more path to resolve before we reach the target directory. Unlike
+            // plain `resolve`, a `Dir` hop recurses back through `read_dir` (not `resolve`) so
+            // the terminal segment still lands in the `(head, None)` branch below, however deep
+            // it is, instead of falling through `resolve`'s generic dispatch, which has no
+            // listing logic of its own.
+            (head, Some(mut tail)) => {
+                let mut result = Err(ErrorKind::NotFound.into());
+                let find = self.nodes.iter().rev().filter(|n| n.name == head || n.name.is_empty());
+
+                for node in find {
+                    if node.name.is_empty() {
+                        tail = path;
+                    }
+
+                    result = match &node.kind {
+                        NodeKind::Mount(mount) => mount.read_dir(tail),
+                        NodeKind::Dir(dir) => dir.read_dir(tail),
+                    };
+
+                    if result.is_ok() {
+                        return result;
+                    }
+                }
+                result
+            }
+
+            // We've arrived at the directory itself (`head` names it, or is empty for root):
+            // union the synthetic child node names with whatever overlapping `Mount`s report.
+            (head, None) => self.list(head, path),
+        }
+    }
+
+    fn stat(&self, path: &VfsPath) -> Result<Metadata> {
+        match path.take_head() {
+            // See the note on `read_dir` above: the `Dir` hop recurses through `stat` so the
+            // terminal segment reaches `stat_named` no matter how deep it is.
+            (head, Some(mut tail)) => {
+                let mut result = Err(ErrorKind::NotFound.into());
+                let find = self.nodes.iter().rev().filter(|n| n.name == head || n.name.is_empty());
+
+                for node in find {
+                    if node.name.is_empty() {
+                        tail = path;
+                    }
+
+                    result = match &node.kind {
+                        NodeKind::Mount(mount) => mount.stat(tail),
+                        NodeKind::Dir(dir) => dir.stat(tail),
+                    };
+
+                    if result.is_ok() {
+                        return result;
+                    }
+                }
+                result
+            }
+            (head, None) => self.stat_named(head, path),
+        }
+    }
+
+    // Lists the children of the node named `head` at this level, merged with whatever any
+    // `Mount` occupying this same slot (by name, or root-mounted with an empty name) reports for
+    // `path`. Dedup keeps the first entry seen, i.e. the most recently mounted one, matching the
+    // last-mounted-wins precedence `resolve`/`open` already use.
+    fn list(&self, head: &str, path: &VfsPath) -> Result<Vec<DirEntry>> {
+        let mut entries: Vec<DirEntry> = Vec::new();
+        let mut found = false;
+
+        for node in self.nodes.iter().rev().filter(|n| n.name == head || n.name.is_empty()) {
+            match &node.kind {
+                NodeKind::Dir(dir) => {
+                    found = true;
+                    for child in &dir.nodes {
+                        if !entries.iter().any(|e| e.name == child.name) {
+                            entries.push(child.as_entry());
+                        }
+                    }
+                }
+                NodeKind::Mount(mount) => {
+                    // A root-mounted ("") mount overlays the whole tree, so it sees the
+                    // untrimmed path; a named mount occupying this slot IS the directory, so
+                    // list its own root.
+                    let sub_path = if node.name.is_empty() {
+                        path
+                    } else {
+                        VfsPath::new("/")
+                    };
+                    if let Ok(sub_entries) = mount.read_dir(sub_path) {
+                        found = true;
+                        for entry in sub_entries {
+                            if !entries.iter().any(|e| e.name == entry.name) {
+                                entries.push(entry);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // At root (empty `head`) this level's own named nodes are the synthetic children.
+        if head.is_empty() {
+            for node in self.nodes.iter().rev().filter(|n| !n.name.is_empty()) {
+                found = true;
+                if !entries.iter().any(|e| e.name == node.name) {
+                    entries.push(node.as_entry());
+                }
+            }
+        }
+
+        if found {
+            Ok(entries)
+        } else {
+            Err(ErrorKind::NotFound.into())
+        }
+    }
+
+    // Same name-matching as `list`, but for `stat`: the first match wins since metadata can't be
+    // merged the way directory listings can.
+    fn stat_named(&self, head: &str, path: &VfsPath) -> Result<Metadata> {
+        for node in self.nodes.iter().rev().filter(|n| n.name == head || n.name.is_empty()) {
+            let result = match &node.kind {
+                NodeKind::Dir(_) => Ok(Metadata {
+                    is_dir: true,
+                    ..Default::default()
+                }),
+                NodeKind::Mount(mount) => {
+                    let sub_path = if node.name.is_empty() {
+                        path
+                    } else {
+                        VfsPath::new("/")
+                    };
+                    mount.stat(sub_path)
+                }
+            };
+            if result.is_ok() {
+                return result;
+            }
+        }
+        Err(ErrorKind::NotFound.into())
+    }
+
+    // Walks `path` using the same FILO node-matching logic `open` has always used, dispatching
+    // to whichever `Mount`/`VirtualDir` is found via `on_mount`. Shared by every verb so adding a
+    // new one doesn't mean re-deriving path resolution.
+    fn resolve<T>(
+        &self,
+        path: &VfsPath,
+        on_mount: &dyn Fn(&dyn Mount, &VfsPath) -> Result<T>,
+    ) -> Result<T> {
+        let mut result = Err(ErrorKind::NotFound.into());
 
         // If tail is none, then we are addressing a directory
         // TODO: possibly handle directory operations?
@@ -96,22 +324,62 @@ impl VirtualDir {
                     tail = path;
                 }
 
-                file = match &node.kind {
-                    NodeKind::Mount(mount) => mount.open(tail),
-                    NodeKind::Dir(dir) => dir.open(tail),
+                result = match &node.kind {
+                    NodeKind::Mount(mount) => on_mount(mount.as_ref(), tail),
+                    NodeKind::Dir(dir) => dir.resolve(tail, on_mount),
                 };
 
-                // If a file is found, return it and be done
+                // If it succeeded, return it and be done
                 // If not, continue iterating. This allows for multiple mounts of the same name
-                if file.is_ok() {
-                    return file;
+                if result.is_ok() {
+                    return result;
                 }
 
                 // TODO: determine if we should handle certain types of errors instead of just continuing
             }
         }
-        // Return that last error held by file
-        file
+        // Return that last error held by result
+        result
+    }
+
+    // Same as `resolve`, but for verbs that need two paths resolved in lockstep (rename, copy).
+    // `to` has its own leading segment stripped alongside `from`'s at every step, without checking
+    // that the two segments match, so both tails stay addressed relative to the mount `from`
+    // lands in.
+    fn resolve2<T>(
+        &self,
+        from: &VfsPath,
+        to: &VfsPath,
+        on_mount: &dyn Fn(&dyn Mount, &VfsPath, &VfsPath) -> Result<T>,
+    ) -> Result<T> {
+        let mut result = Err(ErrorKind::NotFound.into());
+
+        if let (head, Some(mut from_tail)) = from.take_head() {
+            let mut to_tail = to.take_head().1.unwrap_or(to);
+
+            let find = self
+                .nodes
+                .iter()
+                .rev()
+                .filter(|n| n.name == head || n.name.is_empty());
+
+            for node in find {
+                if node.name.is_empty() {
+                    from_tail = from;
+                    to_tail = to;
+                }
+
+                result = match &node.kind {
+                    NodeKind::Mount(mount) => on_mount(mount.as_ref(), from_tail, to_tail),
+                    NodeKind::Dir(dir) => dir.resolve2(from_tail, to_tail, on_mount),
+                };
+
+                if result.is_ok() {
+                    return result;
+                }
+            }
+        }
+        result
     }
 }
 
@@ -159,39 +427,116 @@ impl Node {
             kind: NodeKind::Mount(mount),
         }
     }
+
+    // A synthetic `DirEntry` for this node, used when listing its parent directory. Virtual
+    // nodes don't carry real metadata, so mounts are reported as directories until listed
+    // themselves.
+    fn as_entry(&self) -> DirEntry {
+        DirEntry {
+            name: self.name.clone(),
+            metadata: Metadata {
+                is_dir: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// Options shared by `create_file`/`create_dir`/`copy`: whether an existing entry at the
+// destination should be overwritten or left in place and treated as an error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateOptions {
+    pub overwrite: bool,
+}
+
+// Options for `rename`: whether an existing entry at the destination should be overwritten.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenameOptions {
+    pub overwrite: bool,
+}
+
+// Options for `remove_file`/`remove_dir`: whether a non-empty directory should be removed along
+// with its contents instead of failing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RemoveOptions {
+    pub recursive: bool,
+}
+
+// Metadata about a single virtual or physical entry, as returned by `Mount::stat`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Metadata {
+    pub is_dir: bool,
+    pub is_file: bool,
+    pub len: u64,
+    pub modified: Option<std::time::SystemTime>,
 }
 
+// A single entry returned by `Mount::read_dir`.
+#[derive(Debug, Clone)]
+pub struct DirEntry {
+    pub name: String,
+    pub metadata: Metadata,
+}
+
+// A handle to an open virtual file. Seekable so callers can jump into large bundled assets
+// without reading everything up to that point first. Blanket-implemented for anything that's
+// already `Read + Seek`, e.g. `std::fs::File` or `std::io::Cursor`.
+pub trait VfsFile: Read + Seek {}
+impl<T: Read + Seek> VfsFile for T {}
+
 // The Debug trait bound may be removed in the future
 pub trait Mount: std::fmt::Debug {
     // Opens a virtual file for reading. OpenOptions will be supported in the future.
-    fn open(&self, path: &VfsPath) -> Result<Box<dyn Read>>;
+    fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>>;
+
+    // Write/create/remove operations default to unsupported so read-only mounts (the common
+    // case so far, e.g. `EchoMount` in our tests) still compose without implementing every verb.
+
+    fn create_file(&self, _path: &VfsPath, _options: CreateOptions) -> Result<()> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn remove_file(&self, _path: &VfsPath, _options: RemoveOptions) -> Result<()> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn create_dir(&self, _path: &VfsPath, _options: CreateOptions) -> Result<()> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn remove_dir(&self, _path: &VfsPath, _options: RemoveOptions) -> Result<()> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn rename(&self, _from: &VfsPath, _to: &VfsPath, _options: RenameOptions) -> Result<()> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn copy(&self, _from: &VfsPath, _to: &VfsPath, _options: CreateOptions) -> Result<()> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn read_dir(&self, _path: &VfsPath) -> Result<Vec<DirEntry>> {
+        Err(ErrorKind::Unsupported.into())
+    }
+
+    fn stat(&self, _path: &VfsPath) -> Result<Metadata> {
+        Err(ErrorKind::Unsupported.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::path::*;
     use crate::*;
-    use std::io::Read;
-
-    // A very simple file for testing purposes
-    // Only supports read_to_string
-    struct TestFile(String);
-    impl Read for TestFile {
-        fn read(&mut self, _buf: &mut [u8]) -> Result<usize> {
-            Ok(0)
-        }
-        fn read_to_string(&mut self, buf: &mut String) -> Result<usize> {
-            buf.push_str(&self.0);
-            Ok(self.0.len())
-        }
-    }
+    use std::io::{Cursor, ErrorKind, Read};
 
     // A testing mount that just echoes the paths given to it
     #[derive(Debug)]
     struct EchoMount;
     impl Mount for EchoMount {
-        fn open(&self, path: &VfsPath) -> Result<Box<dyn Read>> {
-            Ok(Box::new(TestFile(path.to_str().to_owned())) as Box<dyn Read>)
+        fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>> {
+            Ok(Box::new(Cursor::new(path.to_str().to_owned().into_bytes())) as Box<dyn VfsFile>)
         }
     }
 
@@ -199,7 +544,7 @@ mod tests {
     #[derive(Debug)]
     struct EmptyMount;
     impl Mount for EmptyMount {
-        fn open(&self, _path: &VfsPath) -> Result<Box<dyn Read>> {
+        fn open(&self, _path: &VfsPath) -> Result<Box<dyn VfsFile>> {
             Err(ErrorKind::NotFound.into())
         }
     }
@@ -229,4 +574,94 @@ mod tests {
         // The mount at "/" will thus be queried and return the untrimmed path
         assert_eq!(contents, String::from("/path/empty/hello/world"));
     }
+
+    #[test]
+    fn mount_write_ops_default_to_unsupported() {
+        let mut vfs = VFS::new();
+        vfs.mount(VfsPath::new("/echo"), Box::new(EchoMount));
+
+        let err = vfs
+            .create_file(VfsPath::new("/echo/file.txt"), CreateOptions::default())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    // A mount whose `read_dir`/`stat` always report a single fixed file, regardless of path
+    #[derive(Debug)]
+    struct SingleFileMount;
+    impl Mount for SingleFileMount {
+        fn open(&self, _path: &VfsPath) -> Result<Box<dyn VfsFile>> {
+            Err(ErrorKind::NotFound.into())
+        }
+        fn read_dir(&self, _path: &VfsPath) -> Result<Vec<DirEntry>> {
+            Ok(vec![DirEntry {
+                name: "file_from_mount.txt".to_owned(),
+                metadata: Metadata {
+                    is_file: true,
+                    ..Default::default()
+                },
+            }])
+        }
+        fn stat(&self, _path: &VfsPath) -> Result<Metadata> {
+            Ok(Metadata {
+                is_file: true,
+                len: 42,
+                ..Default::default()
+            })
+        }
+    }
+
+    #[test]
+    fn read_dir_unions_synthetic_children_with_mount_entries() {
+        let mut vfs = VFS::new();
+        // Creates a synthetic "real" dir with a "sub" child
+        vfs.mount(VfsPath::new("/real/sub"), Box::new(EmptyMount));
+        // Mounts a second, overlapping mount directly at "/real"
+        vfs.mount(VfsPath::new("/real"), Box::new(SingleFileMount));
+
+        let mut entries = vfs.read_dir(VfsPath::new("/real")).unwrap();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["file_from_mount.txt", "sub"]);
+    }
+
+    #[test]
+    fn stat_reports_mount_metadata() {
+        let mut vfs = VFS::new();
+        vfs.mount(VfsPath::new("/data"), Box::new(SingleFileMount));
+
+        let stat = vfs.stat(VfsPath::new("/data")).unwrap();
+        assert!(stat.is_file);
+        assert_eq!(stat.len, 42);
+    }
+
+    #[test]
+    fn read_dir_and_stat_reach_a_mount_nested_two_levels_deep() {
+        let mut vfs = VFS::new();
+        vfs.mount(VfsPath::new("/a/b"), Box::new(SingleFileMount));
+
+        let entries = vfs.read_dir(VfsPath::new("/a/b")).unwrap();
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["file_from_mount.txt"]);
+
+        let stat = vfs.stat(VfsPath::new("/a/b")).unwrap();
+        assert!(stat.is_file);
+        assert_eq!(stat.len, 42);
+    }
+
+    #[test]
+    fn read_dir_and_stat_reach_a_synthetic_dir_nested_two_levels_deep() {
+        let mut vfs = VFS::new();
+        // Mounting at "/a/b/c" synthesizes virtual directories "a" and "b" with no backing
+        // mount, so listing/stat-ing "/a/b" itself must still find the synthetic "c" child.
+        vfs.mount(VfsPath::new("/a/b/c"), Box::new(EmptyMount));
+
+        let entries = vfs.read_dir(VfsPath::new("/a/b")).unwrap();
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["c"]);
+
+        let stat = vfs.stat(VfsPath::new("/a/b")).unwrap();
+        assert!(stat.is_dir);
+    }
 }
@@ -0,0 +1,387 @@
+use crate::path::{VfsPath, VfsPathBuf};
+use crate::{CreateOptions, DirEntry, Metadata, Mount, RemoveOptions, RenameOptions, VfsFile};
+use std::collections::HashSet;
+use std::io::{ErrorKind, Result};
+
+// Builds the whiteout marker for `path`: an empty sibling file named `.wh.<name>`, the same
+// convention overlayfs uses to hide a lower-layer entry that the upper layer can't actually
+// delete.
+fn whiteout_path(path: &VfsPath) -> Option<VfsPathBuf> {
+    let name = path.file_name()?;
+    let mut whiteout = path.parent()?.to_owned();
+    whiteout.push_segment(&format!(".wh.{}", name))?;
+    Some(whiteout)
+}
+
+// A layered mount wrapping one writable upper layer and any number of read-only lower layers.
+// Reads try upper first, then each lower in order; all writes land in upper. Removing an entry
+// that only exists in a lower layer can't actually delete it there, so a whiteout marker is
+// recorded in upper instead, hiding it from subsequent reads and listings.
+#[derive(Debug)]
+pub struct OverlayMount {
+    upper: Box<dyn Mount>,
+    lowers: Vec<Box<dyn Mount>>,
+}
+
+impl OverlayMount {
+    pub fn new(upper: Box<dyn Mount>, lowers: Vec<Box<dyn Mount>>) -> Self {
+        Self { upper, lowers }
+    }
+
+    fn is_whited_out(&self, path: &VfsPath) -> bool {
+        whiteout_path(path)
+            .map(|whiteout| self.upper.stat(&whiteout).is_ok())
+            .unwrap_or(false)
+    }
+
+    // Creates `dir` and every ancestor of it in upper that doesn't already exist there, so a
+    // whiteout (or any other upper write) can land under a path that upper never had a real
+    // directory for, e.g. one that only exists because a lower layer has it.
+    fn ensure_upper_dir(&self, dir: &VfsPath) -> Result<()> {
+        let mut built = VfsPathBuf::new();
+        for segment in dir.iter() {
+            built.push_segment(segment);
+            self.upper
+                .create_dir(&built, CreateOptions { overwrite: true })?;
+        }
+        Ok(())
+    }
+
+    // Records a whiteout in upper for `path`, assuming it's already been confirmed to exist in
+    // some lower layer. Shared by `remove_file`/`remove_dir` since both hide lower entries the
+    // same way. `overwrite: true` makes whiting out an already-whited-out path a no-op instead of
+    // an `AlreadyExists` error.
+    fn whiteout(&self, path: &VfsPath) -> Result<()> {
+        let whiteout =
+            whiteout_path(path).ok_or_else(|| std::io::Error::from(ErrorKind::InvalidInput))?;
+        if let Some(parent) = whiteout.parent() {
+            self.ensure_upper_dir(parent)?;
+        }
+        self.upper
+            .create_file(&whiteout, CreateOptions { overwrite: true })
+    }
+
+    // Clears any whiteout recorded for `path`, so a freshly (re)created upper entry is visible
+    // again. Shared by `create_file`/`create_dir` since both resurrect a path the same way.
+    fn clear_whiteout(&self, path: &VfsPath) -> Result<()> {
+        let whiteout =
+            whiteout_path(path).ok_or_else(|| std::io::Error::from(ErrorKind::InvalidInput))?;
+        match self.upper.remove_file(&whiteout, RemoveOptions::default()) {
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    // Whites out `path` if, after a successful upper removal, it's still visible in some lower
+    // layer. Shared by `remove_file`/`remove_dir`.
+    fn whiteout_if_lower_exists(&self, path: &VfsPath) -> Result<()> {
+        if self.lowers.iter().any(|lower| lower.stat(path).is_ok()) {
+            self.whiteout(path)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Mount for OverlayMount {
+    fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>> {
+        if self.is_whited_out(path) {
+            return Err(ErrorKind::NotFound.into());
+        }
+
+        if let Ok(file) = self.upper.open(path) {
+            return Ok(file);
+        }
+        for lower in &self.lowers {
+            if let Ok(file) = lower.open(path) {
+                return Ok(file);
+            }
+        }
+        Err(ErrorKind::NotFound.into())
+    }
+
+    fn create_file(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.upper.create_file(path, options)?;
+        self.clear_whiteout(path)
+    }
+
+    fn remove_file(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        match self.upper.remove_file(path, options) {
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                if self.lowers.iter().any(|lower| lower.stat(path).is_ok()) {
+                    self.whiteout(path)
+                } else {
+                    Err(ErrorKind::NotFound.into())
+                }
+            }
+            Ok(()) => self.whiteout_if_lower_exists(path),
+            result => result,
+        }
+    }
+
+    fn create_dir(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.upper.create_dir(path, options)?;
+        self.clear_whiteout(path)
+    }
+
+    fn remove_dir(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        match self.upper.remove_dir(path, options) {
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                if self.lowers.iter().any(|lower| lower.stat(path).is_ok()) {
+                    self.whiteout(path)
+                } else {
+                    Err(ErrorKind::NotFound.into())
+                }
+            }
+            Ok(()) => self.whiteout_if_lower_exists(path),
+            result => result,
+        }
+    }
+
+    // TODO: these only operate within upper for now; copying a lower-layer entry up to upper so
+    // it can be renamed/copied ("copy-up") isn't implemented yet.
+    fn rename(&self, from: &VfsPath, to: &VfsPath, options: RenameOptions) -> Result<()> {
+        self.upper.rename(from, to, options)
+    }
+
+    fn copy(&self, from: &VfsPath, to: &VfsPath, options: CreateOptions) -> Result<()> {
+        self.upper.copy(from, to, options)
+    }
+
+    fn read_dir(&self, path: &VfsPath) -> Result<Vec<DirEntry>> {
+        let mut entries: Vec<DirEntry> = Vec::new();
+        let mut whiteouts = HashSet::new();
+        let mut found = false;
+
+        if let Ok(upper_entries) = self.upper.read_dir(path) {
+            found = true;
+            for entry in upper_entries {
+                match entry.name.strip_prefix(".wh.") {
+                    Some(hidden) => {
+                        whiteouts.insert(hidden.to_owned());
+                    }
+                    None => entries.push(entry),
+                }
+            }
+        }
+
+        for lower in &self.lowers {
+            if let Ok(lower_entries) = lower.read_dir(path) {
+                found = true;
+                for entry in lower_entries {
+                    if whiteouts.contains(&entry.name) {
+                        continue;
+                    }
+                    if !entries.iter().any(|e| e.name == entry.name) {
+                        entries.push(entry);
+                    }
+                }
+            }
+        }
+
+        if found {
+            Ok(entries)
+        } else {
+            Err(ErrorKind::NotFound.into())
+        }
+    }
+
+    fn stat(&self, path: &VfsPath) -> Result<Metadata> {
+        if self.is_whited_out(path) {
+            return Err(ErrorKind::NotFound.into());
+        }
+
+        if let Ok(metadata) = self.upper.stat(path) {
+            return Ok(metadata);
+        }
+        for lower in &self.lowers {
+            if let Ok(metadata) = lower.stat(path) {
+                return Ok(metadata);
+            }
+        }
+        Err(ErrorKind::NotFound.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical::PhysicalMount;
+    use crate::VFS;
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read_to_string(vfs: &VFS, path: &str) -> String {
+        let mut file = vfs.open(VfsPath::new(path)).unwrap();
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).unwrap();
+        contents
+    }
+
+    fn mount_overlay(vfs: &mut VFS, target: &str, upper: &Path, lower: &Path) {
+        let overlay = OverlayMount::new(
+            Box::new(PhysicalMount::new(upper).unwrap()),
+            vec![Box::new(PhysicalMount::new(lower).unwrap())],
+        );
+        vfs.mount(VfsPath::new(target), Box::new(overlay));
+    }
+
+    #[test]
+    fn overlay_reads_upper_over_lower() {
+        let lower = scratch_dir("vfs_overlay_lower_read");
+        let upper = scratch_dir("vfs_overlay_upper_read");
+        std::fs::write(lower.join("a.txt"), b"lower").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        assert_eq!(read_to_string(&vfs, "/overlay/a.txt"), "lower");
+
+        vfs.create_file(VfsPath::new("/overlay/a.txt"), CreateOptions { overwrite: true })
+            .unwrap();
+        assert_eq!(read_to_string(&vfs, "/overlay/a.txt"), "");
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+
+    #[test]
+    fn overlay_remove_whiteouts_lower_only_file() {
+        let lower = scratch_dir("vfs_overlay_lower_whiteout");
+        let upper = scratch_dir("vfs_overlay_upper_whiteout");
+        std::fs::write(lower.join("b.txt"), b"hello").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        vfs.remove_file(VfsPath::new("/overlay/b.txt"), RemoveOptions::default())
+            .unwrap();
+
+        assert!(vfs.open(VfsPath::new("/overlay/b.txt")).is_err());
+        assert!(vfs.stat(VfsPath::new("/overlay/b.txt")).is_err());
+        // The lower file itself is untouched; only hidden through the overlay.
+        assert!(lower.join("b.txt").exists());
+        assert!(upper.join(".wh.b.txt").exists());
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+
+    #[test]
+    fn overlay_read_dir_merges_and_hides_whiteouts() {
+        let lower = scratch_dir("vfs_overlay_lower_readdir");
+        let upper = scratch_dir("vfs_overlay_upper_readdir");
+        std::fs::write(lower.join("a.txt"), b"lower-a").unwrap();
+        std::fs::write(lower.join("b.txt"), b"lower-b").unwrap();
+        std::fs::write(upper.join("c.txt"), b"upper-c").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        vfs.remove_file(VfsPath::new("/overlay/b.txt"), RemoveOptions::default())
+            .unwrap();
+
+        let mut entries = vfs.read_dir(VfsPath::new("/overlay")).unwrap();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["a.txt", "c.txt"]);
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+
+    #[test]
+    fn overlay_create_over_whiteout_clears_it() {
+        let lower = scratch_dir("vfs_overlay_lower_create_over_whiteout");
+        let upper = scratch_dir("vfs_overlay_upper_create_over_whiteout");
+        std::fs::write(lower.join("b.txt"), b"hello").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        vfs.remove_file(VfsPath::new("/overlay/b.txt"), RemoveOptions::default())
+            .unwrap();
+        assert!(vfs.open(VfsPath::new("/overlay/b.txt")).is_err());
+
+        vfs.create_file(VfsPath::new("/overlay/b.txt"), CreateOptions::default())
+            .unwrap();
+        assert_eq!(read_to_string(&vfs, "/overlay/b.txt"), "");
+        assert!(!upper.join(".wh.b.txt").exists());
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+
+    #[test]
+    fn overlay_remove_whiteouts_when_present_in_both_layers() {
+        let lower = scratch_dir("vfs_overlay_lower_remove_both");
+        let upper = scratch_dir("vfs_overlay_upper_remove_both");
+        std::fs::write(lower.join("a.txt"), b"LOWER").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        vfs.create_file(
+            VfsPath::new("/overlay/a.txt"),
+            CreateOptions { overwrite: true },
+        )
+        .unwrap();
+        assert_eq!(read_to_string(&vfs, "/overlay/a.txt"), "");
+
+        vfs.remove_file(VfsPath::new("/overlay/a.txt"), RemoveOptions::default())
+            .unwrap();
+
+        assert!(vfs.open(VfsPath::new("/overlay/a.txt")).is_err());
+        assert!(upper.join(".wh.a.txt").exists());
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+
+    #[test]
+    fn overlay_remove_whiteouts_lower_only_file_in_subdir() {
+        let lower = scratch_dir("vfs_overlay_lower_whiteout_subdir");
+        let upper = scratch_dir("vfs_overlay_upper_whiteout_subdir");
+        std::fs::create_dir(lower.join("sub")).unwrap();
+        std::fs::write(lower.join("sub/b.txt"), b"hello").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        vfs.remove_file(VfsPath::new("/overlay/sub/b.txt"), RemoveOptions::default())
+            .unwrap();
+
+        assert!(vfs.open(VfsPath::new("/overlay/sub/b.txt")).is_err());
+        assert!(upper.join("sub/.wh.b.txt").exists());
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+
+    #[test]
+    fn overlay_remove_is_idempotent_on_an_already_whited_out_file() {
+        let lower = scratch_dir("vfs_overlay_lower_whiteout_twice");
+        let upper = scratch_dir("vfs_overlay_upper_whiteout_twice");
+        std::fs::write(lower.join("b.txt"), b"hello").unwrap();
+
+        let mut vfs = VFS::new();
+        mount_overlay(&mut vfs, "/overlay", &upper, &lower);
+
+        vfs.remove_file(VfsPath::new("/overlay/b.txt"), RemoveOptions::default())
+            .unwrap();
+        vfs.remove_file(VfsPath::new("/overlay/b.txt"), RemoveOptions::default())
+            .unwrap();
+
+        assert!(vfs.open(VfsPath::new("/overlay/b.txt")).is_err());
+
+        std::fs::remove_dir_all(&lower).unwrap();
+        std::fs::remove_dir_all(&upper).unwrap();
+    }
+}
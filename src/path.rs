@@ -4,6 +4,14 @@ use std::ops::Deref;
 
 // For the purpose of our VFS, _all_ paths will be considered absolute. We _may_ implement relative paths at some point or another.
 
+// Finds where `substr` sits byte-wise within `parent`, assuming `substr` was derived from
+// `parent` (e.g. via `split`), so pointer arithmetic can locate it directly without a scan.
+fn end_offset_in(substr: &str, parent: &str) -> usize {
+    let substr_ptr = substr.as_ptr() as usize;
+    let parent_ptr = parent.as_ptr() as usize;
+    substr_ptr - parent_ptr + substr.len()
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct VfsPath(str);
 
@@ -18,9 +26,34 @@ impl VfsPath {
     // Windows says file: ".txt" is a text file
     // Do we need to worry about extensions anyway?
     pub fn extension(&self) -> Option<&str> {
-        self.iter()
-            .last()
-            .and_then(|last| last.split('.').skip(1).last())
+        self.file_name()
+            .and_then(|name| name.split('.').skip(1).last())
+    }
+
+    // The last path segment, e.g. "file.txt" for "/hello/world/file.txt"
+    pub fn file_name(&self) -> Option<&str> {
+        self.iter().last()
+    }
+
+    // `file_name` with everything from the first '.' onward stripped, consistent with
+    // `extension`'s rules (e.g. "file" for both "file.txt" and "file")
+    pub fn file_stem(&self) -> Option<&str> {
+        self.file_name().and_then(|name| name.split('.').next())
+    }
+
+    // Everything before the last path segment, e.g. "/hello/world" for "/hello/world/file.txt".
+    // Zero-copy: borrows directly out of `self`, same trick as `new`.
+    pub fn parent(&self) -> Option<&Self> {
+        let last = self.iter().last()?;
+        let start = end_offset_in(last, &self.0) - last.len();
+        let before = self.0[..start].trim_end_matches('/');
+
+        Some(Self::new(if before.is_empty() { "/" } else { before }))
+    }
+
+    // Appends a relative `VfsPath` onto the end of this one
+    pub fn join(&self, relative: &Self) -> VfsPathBuf {
+        self.iter().chain(relative.iter()).collect()
     }
 
     pub fn canonicalize(&self) -> VfsPathBuf {
@@ -41,18 +74,11 @@ impl VfsPath {
 
     // Takes the first folder of the path and returns the rest of the path if there is any left
     pub fn take_head(&self) -> (&str, Option<&Self>) {
-        // find where in the parent a substring is
-        fn offset_in(substr: &str, parent: &str) -> usize {
-            let substr_ptr = substr.as_ptr() as usize;
-            let parent_ptr = parent.as_ptr() as usize;
-            substr_ptr - parent_ptr + substr.len()
-        }
-
         let trimmed = Self::new(self.0.trim_start_matches('/'));
 
         match trimmed.iter().next() {
             Some(take) => {
-                let tail = &trimmed.0[offset_in(take, trimmed.to_str())..];
+                let tail = &trimmed.0[end_offset_in(take, trimmed.to_str())..];
                 let tail = Self::new(tail);
 
                 // If the tail has no more valid path, return none
@@ -69,6 +95,14 @@ impl VfsPath {
         &self.0
     }
 
+    // Converts to a relative `std::path::PathBuf` suitable for joining onto a physical folder.
+    // Fails the same way `validate()` does, e.g. on ".." path selectors.
+    pub fn to_path(&self) -> std::io::Result<std::path::PathBuf> {
+        self.validate()
+            .map(|valid| std::path::PathBuf::from(valid.0.trim_start_matches('/')))
+            .map_err(|_| std::io::ErrorKind::InvalidInput.into())
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &str> {
         self.0.split('/').filter(|s| !s.is_empty() && *s != ".")
     }
@@ -81,13 +115,43 @@ impl ToOwned for VfsPath {
     }
 }
 
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Debug, Default, Eq, PartialEq, Hash)]
 pub struct VfsPathBuf(String);
 
 impl VfsPathBuf {
     pub fn new() -> Self {
         Default::default()
     }
+
+    // Appends a single path segment. Returns `None` (leaving `self` unchanged) if `segment`
+    // contains a '/', since that would actually be multiple segments.
+    pub fn push_segment(&mut self, segment: &str) -> Option<()> {
+        if segment.contains('/') {
+            return None;
+        }
+
+        if !self.0.ends_with('/') {
+            self.0.push('/');
+        }
+        self.0.push_str(segment);
+
+        Some(())
+    }
+
+    // Removes the last path segment, same as `VfsPath::parent` but in place. Returns `None` if
+    // already at the root, since there is nothing left to pop.
+    pub fn pop(&mut self) -> Option<()> {
+        let last = self.iter().last()?;
+        let start = end_offset_in(last, &self.0) - last.len();
+        let truncate_at = self.0[..start].trim_end_matches('/').len();
+
+        if truncate_at == 0 {
+            self.0 = String::from("/");
+        } else {
+            self.0.truncate(truncate_at);
+        }
+        Some(())
+    }
 }
 
 impl Deref for VfsPathBuf {
@@ -229,4 +293,62 @@ mod tests {
         let path = VfsPathBuf::from("/this/is/a/path.txt");
         assert_eq!(path, path.iter().collect());
     }
+
+    #[test]
+    fn path_parent() {
+        let path = VfsPath::new("/hello/world/file.txt");
+        assert_eq!(path.parent(), Some(VfsPath::new("/hello/world")));
+
+        let path = VfsPath::new("/file.txt");
+        assert_eq!(path.parent(), Some(VfsPath::new("/")));
+
+        let path = VfsPath::new("/");
+        assert_eq!(path.parent(), None);
+    }
+
+    #[test]
+    fn path_file_name_and_stem() {
+        let path = VfsPath::new("/hello/world/file.txt");
+        assert_eq!(path.file_name(), Some("file.txt"));
+        assert_eq!(path.file_stem(), Some("file"));
+
+        let path = VfsPath::new("/");
+        assert_eq!(path.file_name(), None);
+        assert_eq!(path.file_stem(), None);
+    }
+
+    #[test]
+    fn path_join() {
+        let base = VfsPath::new("/hello/world");
+        let joined = base.join(VfsPath::new("file.txt"));
+        assert_eq!(joined, VfsPathBuf::from("/hello/world/file.txt"));
+
+        let joined = base.join(VfsPath::new("/nested/file.txt"));
+        assert_eq!(joined, VfsPathBuf::from("/hello/world/nested/file.txt"));
+    }
+
+    #[test]
+    fn path_buf_push_segment() {
+        let mut path = VfsPathBuf::from("/hello");
+        path.push_segment("world").unwrap();
+        assert_eq!(path, VfsPathBuf::from("/hello/world"));
+
+        assert_eq!(path.push_segment("a/b"), None);
+        assert_eq!(path, VfsPathBuf::from("/hello/world"));
+    }
+
+    #[test]
+    fn path_buf_pop() {
+        let mut path = VfsPathBuf::from("/hello/world/file.txt");
+        path.pop().unwrap();
+        assert_eq!(path, VfsPathBuf::from("/hello/world"));
+
+        path.pop().unwrap();
+        assert_eq!(path, VfsPathBuf::from("/hello"));
+
+        path.pop().unwrap();
+        assert_eq!(path, VfsPathBuf::from("/"));
+
+        assert_eq!(path.pop(), None);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::path::VfsPath;
-use crate::Mount;
+use crate::{CreateOptions, DirEntry, Metadata, Mount, RemoveOptions, RenameOptions, VfsFile};
 use std::fs::File;
-use std::io::{ErrorKind, Read, Result};
+use std::io::{ErrorKind, Result};
 use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
@@ -21,10 +21,87 @@ impl PhysicalMount {
 }
 
 impl Mount for PhysicalMount {
-    fn open(&self, path: &VfsPath) -> Result<Box<dyn Read>> {
+    fn open(&self, path: &VfsPath) -> Result<Box<dyn VfsFile>> {
         let joined = self.folder.join(path.to_path()?);
         Ok(Box::new(File::open(joined)?))
     }
+
+    fn create_file(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        let joined = self.folder.join(path.to_path()?);
+        if !options.overwrite && joined.exists() {
+            return Err(ErrorKind::AlreadyExists.into());
+        }
+        File::create(joined)?;
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &VfsPath, _options: RemoveOptions) -> Result<()> {
+        let joined = self.folder.join(path.to_path()?);
+        std::fs::remove_file(joined)
+    }
+
+    fn create_dir(&self, path: &VfsPath, options: CreateOptions) -> Result<()> {
+        let joined = self.folder.join(path.to_path()?);
+        match std::fs::create_dir(joined) {
+            Err(err) if options.overwrite && err.kind() == ErrorKind::AlreadyExists => Ok(()),
+            result => result,
+        }
+    }
+
+    fn remove_dir(&self, path: &VfsPath, options: RemoveOptions) -> Result<()> {
+        let joined = self.folder.join(path.to_path()?);
+        if options.recursive {
+            std::fs::remove_dir_all(joined)
+        } else {
+            std::fs::remove_dir(joined)
+        }
+    }
+
+    fn rename(&self, from: &VfsPath, to: &VfsPath, options: RenameOptions) -> Result<()> {
+        let from = self.folder.join(from.to_path()?);
+        let to = self.folder.join(to.to_path()?);
+        if !options.overwrite && to.exists() {
+            return Err(ErrorKind::AlreadyExists.into());
+        }
+        std::fs::rename(from, to)
+    }
+
+    fn copy(&self, from: &VfsPath, to: &VfsPath, options: CreateOptions) -> Result<()> {
+        let from = self.folder.join(from.to_path()?);
+        let to = self.folder.join(to.to_path()?);
+        if !options.overwrite && to.exists() {
+            return Err(ErrorKind::AlreadyExists.into());
+        }
+        std::fs::copy(from, to)?;
+        Ok(())
+    }
+
+    fn read_dir(&self, path: &VfsPath) -> Result<Vec<DirEntry>> {
+        let joined = self.folder.join(path.to_path()?);
+        std::fs::read_dir(joined)?
+            .map(|entry| {
+                let entry = entry?;
+                Ok(DirEntry {
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    metadata: metadata_from_std(&entry.metadata()?),
+                })
+            })
+            .collect()
+    }
+
+    fn stat(&self, path: &VfsPath) -> Result<Metadata> {
+        let joined = self.folder.join(path.to_path()?);
+        Ok(metadata_from_std(&std::fs::metadata(joined)?))
+    }
+}
+
+fn metadata_from_std(metadata: &std::fs::Metadata) -> Metadata {
+    Metadata {
+        is_dir: metadata.is_dir(),
+        is_file: metadata.is_file(),
+        len: metadata.len(),
+        modified: metadata.modified().ok(),
+    }
 }
 
 #[cfg(test)]
@@ -32,6 +109,7 @@ mod tests {
     use super::*;
     use crate::path::VfsPath;
     use crate::VFS;
+    use std::io::{Read, Seek, SeekFrom};
 
     #[test]
     fn physical_mount() {
@@ -49,4 +127,80 @@ mod tests {
 
         assert_eq!(vfs_contents, real_contents);
     }
+
+    #[test]
+    fn physical_mount_open_is_seekable() {
+        let mut vfs = VFS::new();
+        vfs.mount_physical(VfsPath::new("/random/path"), Path::new("./src"))
+            .unwrap();
+
+        let mut vfs_file = vfs.open(VfsPath::new("/random/path/path.rs")).unwrap();
+        vfs_file.seek(SeekFrom::Start(3)).unwrap();
+
+        let mut from_offset = Vec::new();
+        vfs_file.read_to_end(&mut from_offset).unwrap();
+
+        let real_contents = std::fs::read("./src/path.rs").unwrap();
+        assert_eq!(from_offset, real_contents[3..]);
+    }
+
+    #[test]
+    fn physical_mount_write_ops() {
+        let scratch = std::env::temp_dir().join("vfs_physical_write_ops_test");
+        std::fs::create_dir_all(&scratch).unwrap();
+
+        let mut vfs = VFS::new();
+        vfs.mount_physical(VfsPath::new("/scratch"), &scratch)
+            .unwrap();
+
+        vfs.create_file(VfsPath::new("/scratch/a.txt"), CreateOptions::default())
+            .unwrap();
+        assert!(vfs
+            .create_file(VfsPath::new("/scratch/a.txt"), CreateOptions::default())
+            .is_err());
+        vfs.create_file(
+            VfsPath::new("/scratch/a.txt"),
+            CreateOptions { overwrite: true },
+        )
+        .unwrap();
+
+        vfs.rename(
+            VfsPath::new("/scratch/a.txt"),
+            VfsPath::new("/scratch/b.txt"),
+            RenameOptions::default(),
+        )
+        .unwrap();
+        assert!(scratch.join("b.txt").exists());
+
+        vfs.copy(
+            VfsPath::new("/scratch/b.txt"),
+            VfsPath::new("/scratch/c.txt"),
+            CreateOptions::default(),
+        )
+        .unwrap();
+        assert!(scratch.join("c.txt").exists());
+
+        vfs.remove_file(VfsPath::new("/scratch/b.txt"), RemoveOptions::default())
+            .unwrap();
+        vfs.remove_file(VfsPath::new("/scratch/c.txt"), RemoveOptions::default())
+            .unwrap();
+
+        std::fs::remove_dir_all(&scratch).unwrap();
+    }
+
+    #[test]
+    fn physical_mount_read_dir_and_stat() {
+        let mut vfs = VFS::new();
+        vfs.mount_physical(VfsPath::new("/src"), Path::new("./src"))
+            .unwrap();
+
+        let entries = vfs.read_dir(VfsPath::new("/src")).unwrap();
+        assert!(entries.iter().any(|e| e.name == "lib.rs" && e.metadata.is_file));
+        assert!(entries.iter().any(|e| e.name == "path.rs" && e.metadata.is_file));
+
+        let stat = vfs.stat(VfsPath::new("/src/lib.rs")).unwrap();
+        assert!(stat.is_file);
+        assert!(!stat.is_dir);
+        assert!(stat.len > 0);
+    }
 }